@@ -0,0 +1,164 @@
+//! An authenticated duplex channel built on a synchronized [`Strobe`].
+//!
+//! [`Channel`] wraps the `Strobe` returned by [`Exchanger::receive`]: each call to
+//! [`seal`][Channel::seal] or [`open`][Channel::open] appends or verifies a MAC and advances the
+//! running transcript, so messages are ordered and replay-resistant within the session.
+//!
+//! [`Strobe`]: strobe_rs::Strobe
+//! [`Exchanger::receive`]: crate::Exchanger::receive
+
+use alloc::vec::Vec;
+
+use core::fmt;
+
+use strobe_rs::Strobe;
+
+use crate::TAG_LEN;
+
+/// An error sealing or opening a [Channel] message.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ChannelError {
+    /// The ciphertext was too short to contain a MAC tag.
+    Truncated,
+    /// The MAC tag didn't match, meaning the ciphertext was corrupted, reordered, or forged.
+    Forged,
+}
+
+impl fmt::Display for ChannelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelError::Truncated => write!(f, "ciphertext too short to contain a MAC tag"),
+            ChannelError::Forged => write!(f, "channel message MAC tag mismatch"),
+        }
+    }
+}
+
+/// An authenticated duplex channel, built on the [`Strobe`] returned by [`Exchanger::receive`].
+///
+/// Both parties construct a `Channel` from their copy of the synchronized `Strobe`; whichever
+/// side calls [`seal`][Channel::seal] for a message, the other calls [`open`][Channel::open] on
+/// it, in the order the messages were sent.
+///
+/// [`Strobe`]: strobe_rs::Strobe
+/// [`Exchanger::receive`]: crate::Exchanger::receive
+pub struct Channel {
+    strobe: Strobe,
+}
+
+impl Channel {
+    /// Wrap a synchronized `Strobe` in a [Channel].
+    pub const fn new(strobe: Strobe) -> Channel {
+        Channel { strobe }
+    }
+
+    /// Encrypt and authenticate `plaintext`, returning the ciphertext to send to the peer.
+    pub fn seal(&mut self, plaintext: &[u8]) -> Vec<u8> {
+        let mut ciphertext = plaintext.to_vec();
+        self.strobe.send_enc(&mut ciphertext, false);
+
+        let mut tag = [0u8; TAG_LEN];
+        self.strobe.send_mac(&mut tag, false);
+        ciphertext.extend_from_slice(&tag);
+
+        ciphertext
+    }
+
+    /// Verify and decrypt a `ciphertext` produced by the peer's [`seal`][Channel::seal].
+    pub fn open(&mut self, ciphertext: &[u8]) -> Result<Vec<u8>, ChannelError> {
+        if ciphertext.len() < TAG_LEN {
+            return Err(ChannelError::Truncated);
+        }
+
+        let (ciphertext, tag) = ciphertext.split_at(ciphertext.len() - TAG_LEN);
+        let mut plaintext = ciphertext.to_vec();
+        self.strobe.recv_enc(&mut plaintext, false);
+
+        let mut buf = [0u8; TAG_LEN];
+        buf.copy_from_slice(tag);
+        self.strobe.recv_mac(&buf).map_err(|_| ChannelError::Forged)?;
+
+        Ok(plaintext)
+    }
+
+    /// Ratchet the channel's state forward, making it infeasible to recover prior channel keys
+    /// from the current one. Useful for long-lived connections that want forward secrecy
+    /// independent of how long the underlying session key has been in use.
+    pub fn rekey(&mut self) {
+        self.strobe.ratchet(32, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Exchanger, Role};
+
+    fn channels() -> (Channel, Channel) {
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+        let y_alice = alice.send();
+
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"secret", b"");
+        let y_bea = bea.send();
+
+        let alice = alice.receive(y_bea);
+        let bea = bea.receive(y_alice);
+
+        (Channel::new(alice), Channel::new(bea))
+    }
+
+    #[test]
+    fn round_trip() {
+        let (mut alice, mut bea) = channels();
+
+        let ciphertext = alice.seal(b"ping");
+        assert_eq!(bea.open(&ciphertext).expect("should open"), b"ping");
+
+        let ciphertext = bea.seal(b"pong");
+        assert_eq!(alice.open(&ciphertext).expect("should open"), b"pong");
+    }
+
+    #[test]
+    fn forged_ciphertext() {
+        let (mut alice, mut bea) = channels();
+
+        let mut ciphertext = alice.seal(b"ping");
+        let n = ciphertext.len();
+        ciphertext[n - 1] ^= 1;
+
+        assert_eq!(bea.open(&ciphertext), Err(ChannelError::Forged));
+    }
+
+    #[test]
+    fn truncated_ciphertext() {
+        let (_alice, mut bea) = channels();
+
+        assert_eq!(bea.open(&[0u8; 4]), Err(ChannelError::Truncated));
+    }
+
+    #[test]
+    fn rekey_breaks_ordering() {
+        let (mut alice, mut bea) = channels();
+
+        let ciphertext = alice.seal(b"ping");
+        bea.rekey();
+
+        assert_eq!(bea.open(&ciphertext), Err(ChannelError::Forged));
+    }
+
+    #[test]
+    fn rekey_round_trip() {
+        let (mut alice, mut bea) = channels();
+
+        let ciphertext = alice.seal(b"ping");
+        assert_eq!(bea.open(&ciphertext).expect("should open"), b"ping");
+
+        alice.rekey();
+        bea.rekey();
+
+        let ciphertext = alice.seal(b"pong");
+        assert_eq!(bea.open(&ciphertext).expect("should open"), b"pong");
+
+        let ciphertext = bea.seal(b"ack");
+        assert_eq!(alice.open(&ciphertext).expect("should open"), b"ack");
+    }
+}
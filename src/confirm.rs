@@ -0,0 +1,124 @@
+//! Explicit key confirmation over a synchronized [`Strobe`].
+//!
+//! [`Confirm`] exchanges a MAC tag in each direction over the transcript from
+//! [`Exchanger::receive`], turning a mismatched password, identity, or session ID into an
+//! explicit error instead of a silently divergent key.
+//!
+//! Callers MAC in the same order [`Exchanger::receive`] already establishes for points: the
+//! initiator sends its tag before checking the responder's, while the responder checks the
+//! initiator's tag before sending its own.
+//!
+//! ```ignore
+//! // initiator
+//! let tag = cpace.send_confirmation();
+//! // ...transmit `tag`, receive the responder's tag as `their_tag`...
+//! let cpace = cpace.recv_confirmation(&their_tag)?;
+//!
+//! // responder
+//! // ...receive the initiator's tag as `their_tag`...
+//! let mut cpace = cpace.recv_confirmation(&their_tag)?;
+//! let tag = cpace.send_confirmation();
+//! // ...transmit `tag`...
+//! ```
+
+use core::fmt;
+
+use strobe_rs::Strobe;
+
+/// The length, in bytes, of a key-confirmation MAC tag.
+pub const TAG_LEN: usize = 16;
+
+/// An error confirming that both parties derived the same key.
+#[derive(Debug, Eq, PartialEq)]
+pub enum ConfirmError {
+    /// The peer's tag didn't match the locally computed one, meaning the two parties derived
+    /// different keys (e.g. from a mismatched password, identity, or session ID).
+    Mismatch,
+}
+
+impl fmt::Display for ConfirmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfirmError::Mismatch => write!(f, "key confirmation tag mismatch"),
+        }
+    }
+}
+
+/// Key confirmation for a synchronized [`Strobe`].
+///
+/// [`Strobe`]: strobe_rs::Strobe
+pub trait Confirm: Sized {
+    /// Produce a MAC tag confirming the derived key, to send to the peer.
+    fn send_confirmation(&mut self) -> [u8; TAG_LEN];
+
+    /// Check a MAC tag received from the peer, confirming they derived the same key.
+    fn recv_confirmation(self, tag: &[u8]) -> Result<Self, ConfirmError>;
+}
+
+impl Confirm for Strobe {
+    fn send_confirmation(&mut self) -> [u8; TAG_LEN] {
+        let mut tag = [0u8; TAG_LEN];
+        self.send_mac(&mut tag, false);
+        tag
+    }
+
+    fn recv_confirmation(mut self, tag: &[u8]) -> Result<Self, ConfirmError> {
+        if tag.len() != TAG_LEN {
+            return Err(ConfirmError::Mismatch);
+        }
+        let mut buf = [0u8; TAG_LEN];
+        buf.copy_from_slice(tag);
+        self.recv_mac(&buf).map_err(|_| ConfirmError::Mismatch)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Exchanger, Role};
+
+    #[test]
+    fn full_confirmation() {
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+        let y_alice = alice.send();
+
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"secret", b"");
+        let y_bea = bea.send();
+
+        let mut alice = alice.receive(y_bea);
+        let bea = bea.receive(y_alice);
+
+        // Bea checks Alice's tag first, then sends her own.
+        let alice_tag = alice.send_confirmation();
+        let mut bea = bea.recv_confirmation(&alice_tag).expect("should confirm");
+        let bea_tag = bea.send_confirmation();
+        alice.recv_confirmation(&bea_tag).expect("should confirm");
+    }
+
+    #[test]
+    fn bad_password_confirmation() {
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+        let y_alice = alice.send();
+
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"dingus", b"");
+        let y_bea = bea.send();
+
+        let mut alice = alice.receive(y_bea);
+        let bea = bea.receive(y_alice);
+
+        let alice_tag = alice.send_confirmation();
+        assert!(matches!(bea.recv_confirmation(&alice_tag), Err(ConfirmError::Mismatch)));
+    }
+
+    #[test]
+    fn wrong_length_tag() {
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"secret", b"");
+        let y_bea = bea.send();
+
+        let alice = alice.receive(y_bea);
+        assert!(matches!(alice.recv_confirmation(&[0u8; 4]), Err(ConfirmError::Mismatch)));
+    }
+}
@@ -0,0 +1,210 @@
+//! A post-quantum, hybrid variant of the CPACE exchange.
+//!
+//! This augments the ristretto255 key exchange in [`Exchanger`] with a Kyber768 KEM
+//! encapsulation, so the session key stays confidential even if ristretto255 is later broken by
+//! a quantum adversary. The KEM is one-directional (only the initiator holds a keypair to
+//! encapsulate against), so unlike [`Exchanger`] the two roles are distinct types exchanging
+//! distinct messages, rather than a single type calling a symmetric `send`/`receive`.
+
+use core::fmt;
+
+use curve25519_dalek::ristretto::CompressedRistretto;
+use pqcrypto_kyber::kyber768;
+use pqcrypto_traits::kem::{
+    Ciphertext as _, PublicKey as _, SharedSecret as _,
+};
+use strobe_rs::Strobe;
+
+use crate::{Exchanger, RistrettoPoint, Role};
+
+/// The initiator's half of a hybrid exchange.
+///
+/// Created with [`HybridInitiator::new`], it generates a Kyber768 keypair alongside the usual
+/// ristretto255 point so the responder can encapsulate a KEM shared secret against it.
+pub struct HybridInitiator {
+    exchanger: Exchanger,
+    kyber_pk: kyber768::PublicKey,
+    kyber_sk: kyber768::SecretKey,
+}
+
+/// The responder's half of a hybrid exchange.
+pub struct HybridResponder {
+    exchanger: Exchanger,
+}
+
+/// The message sent by a [HybridInitiator] to a [HybridResponder].
+pub struct HybridInitiatorMessage {
+    /// The initiator's ristretto255 point.
+    pub y: RistrettoPoint,
+    /// The initiator's Kyber768 public key.
+    pub kyber_pk: kyber768::PublicKey,
+}
+
+/// The message sent by a [HybridResponder] back to a [HybridInitiator].
+pub struct HybridResponderMessage {
+    /// The responder's ristretto255 point.
+    pub y: RistrettoPoint,
+    /// A Kyber768 ciphertext, encapsulated against the initiator's public key.
+    pub kyber_ct: kyber768::Ciphertext,
+}
+
+/// An error completing a hybrid exchange.
+#[derive(Debug, Eq, PartialEq)]
+pub enum HybridError {
+    /// The peer's ristretto255 point did not decompress to a canonical point.
+    InvalidPoint,
+    /// The peer's Kyber768 public key was malformed or the wrong length.
+    InvalidPublicKey,
+    /// The peer's Kyber768 ciphertext was malformed or the wrong length.
+    InvalidCiphertext,
+}
+
+impl fmt::Display for HybridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HybridError::InvalidPoint => write!(f, "invalid ristretto255 point"),
+            HybridError::InvalidPublicKey => write!(f, "invalid kyber768 public key"),
+            HybridError::InvalidCiphertext => write!(f, "invalid kyber768 ciphertext"),
+        }
+    }
+}
+
+impl HybridInitiatorMessage {
+    /// Parse a message from wire bytes, rejecting a malformed point or the wrong-length public
+    /// key before it's used for anything.
+    pub fn from_bytes(y: &[u8], kyber_pk: &[u8]) -> Result<HybridInitiatorMessage, HybridError> {
+        let y = CompressedRistretto::from_slice(y)
+            .map_err(|_| HybridError::InvalidPoint)?
+            .decompress()
+            .ok_or(HybridError::InvalidPoint)?;
+        let kyber_pk =
+            kyber768::PublicKey::from_bytes(kyber_pk).map_err(|_| HybridError::InvalidPublicKey)?;
+        Ok(HybridInitiatorMessage { y, kyber_pk })
+    }
+}
+
+impl HybridResponderMessage {
+    /// Parse a message from wire bytes, rejecting a malformed point or a malformed or
+    /// wrong-length ciphertext before it's used for anything.
+    pub fn from_bytes(y: &[u8], kyber_ct: &[u8]) -> Result<HybridResponderMessage, HybridError> {
+        let y = CompressedRistretto::from_slice(y)
+            .map_err(|_| HybridError::InvalidPoint)?
+            .decompress()
+            .ok_or(HybridError::InvalidPoint)?;
+        let kyber_ct =
+            kyber768::Ciphertext::from_bytes(kyber_ct).map_err(|_| HybridError::InvalidCiphertext)?;
+        Ok(HybridResponderMessage { y, kyber_ct })
+    }
+}
+
+impl HybridInitiator {
+    /// Create a new [HybridInitiator] with the given identities, shared password, and optional
+    /// session ID.
+    pub fn new(
+        local_id: &[u8],
+        remote_id: &[u8],
+        password: &[u8],
+        session_id: &[u8],
+    ) -> HybridInitiator {
+        let exchanger = Exchanger::new(Role::INITIATOR, local_id, remote_id, password, session_id);
+        let (kyber_pk, kyber_sk) = kyber768::keypair();
+        HybridInitiator { exchanger, kyber_pk, kyber_sk }
+    }
+
+    /// The message to send to the responder.
+    pub const fn send(&self) -> HybridInitiatorMessage {
+        HybridInitiatorMessage { y: self.exchanger.send(), kyber_pk: self.kyber_pk }
+    }
+
+    /// Given the responder's message, unwrap the exchange into a synchronized Strobe protocol
+    /// keyed with both the ristretto255 DH contribution and the Kyber768 KEM secret.
+    pub fn receive(self, msg: HybridResponderMessage) -> Strobe {
+        let kyber_ss = kyber768::decapsulate(&msg.kyber_ct, &self.kyber_sk);
+        let mut cpace = self.exchanger.receive(msg.y);
+        cpace.key(kyber_ss.as_bytes(), false);
+        cpace
+    }
+}
+
+impl HybridResponder {
+    /// Create a new [HybridResponder] with the given identities, shared password, and optional
+    /// session ID.
+    pub fn new(
+        local_id: &[u8],
+        remote_id: &[u8],
+        password: &[u8],
+        session_id: &[u8],
+    ) -> HybridResponder {
+        HybridResponder {
+            exchanger: Exchanger::new(Role::RESPONDER, local_id, remote_id, password, session_id),
+        }
+    }
+
+    /// Given the initiator's message, encapsulate a Kyber768 shared secret against its public
+    /// key and unwrap the exchange into a synchronized Strobe protocol, returning the message to
+    /// send back to the initiator alongside it.
+    pub fn receive(self, msg: HybridInitiatorMessage) -> (HybridResponderMessage, Strobe) {
+        let (kyber_ss, kyber_ct) = kyber768::encapsulate(&msg.kyber_pk);
+        let y = self.exchanger.send();
+        let mut cpace = self.exchanger.receive(msg.y);
+        cpace.key(kyber_ss.as_bytes(), false);
+        (HybridResponderMessage { y, kyber_ct }, cpace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_exchange() {
+        let alice = HybridInitiator::new(b"Alice", b"Bea", b"secret", b"");
+        let alice_msg = alice.send();
+
+        let bea = HybridResponder::new(b"Bea", b"Alice", b"secret", b"");
+        let (bea_msg, mut bea) = bea.receive(alice_msg);
+
+        let mut alice = alice.receive(bea_msg);
+
+        let mut prf_a = [0u8; 16];
+        alice.prf(&mut prf_a, false);
+
+        let mut prf_b = [0u8; 16];
+        bea.prf(&mut prf_b, false);
+
+        assert_eq!(prf_b, prf_a);
+    }
+
+    #[test]
+    fn bad_initiator_point() {
+        let kyber_pk = HybridInitiator::new(b"Alice", b"Bea", b"secret", b"").send().kyber_pk;
+
+        assert!(matches!(
+            HybridInitiatorMessage::from_bytes(&[0xffu8; 32], kyber_pk.as_bytes()),
+            Err(HybridError::InvalidPoint)
+        ));
+    }
+
+    #[test]
+    fn bad_initiator_public_key() {
+        let y = HybridInitiator::new(b"Alice", b"Bea", b"secret", b"").send().y.compress().to_bytes();
+
+        assert!(matches!(
+            HybridInitiatorMessage::from_bytes(&y, &[0u8; 4]),
+            Err(HybridError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn bad_responder_ciphertext() {
+        let alice = HybridInitiator::new(b"Alice", b"Bea", b"secret", b"");
+        let bea = HybridResponder::new(b"Bea", b"Alice", b"secret", b"");
+        let (bea_msg, _) = bea.receive(alice.send());
+        let y = bea_msg.y.compress().to_bytes();
+
+        assert!(matches!(
+            HybridResponderMessage::from_bytes(&y, &[0u8; 4]),
+            Err(HybridError::InvalidCiphertext)
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+//! An async handshake driver over `AsyncRead + AsyncWrite`.
+//!
+//! [`exchange`] frames each ristretto255 point as a fixed 32 bytes, picks the send-then-receive
+//! or receive-then-send order from the [`Exchanger`]'s [`Role`] so an initiator and a responder
+//! driving the same transport don't deadlock, and validates that the peer's frame decompresses to
+//! a canonical point before keying.
+
+use core::fmt;
+
+use curve25519_dalek::ristretto::CompressedRistretto;
+use futures::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use strobe_rs::Strobe;
+
+use crate::{Exchanger, Role};
+
+/// An error driving an [`Exchanger`] exchange over a transport.
+#[derive(Debug)]
+pub enum ExchangeError {
+    /// Reading or writing the transport failed.
+    Io(futures::io::Error),
+    /// The peer's point did not decompress to a canonical ristretto255 element.
+    InvalidPoint,
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExchangeError::Io(e) => write!(f, "i/o error: {e}"),
+            ExchangeError::InvalidPoint => write!(f, "peer sent a non-canonical ristretto255 point"),
+        }
+    }
+}
+
+/// Drive a full [`Exchanger`] exchange over `transport`, framing each ristretto255 point as a
+/// fixed 32 bytes, and return the synchronized `Strobe` on success.
+pub async fn exchange<T>(
+    exchanger: Exchanger,
+    transport: &mut T,
+) -> Result<Strobe, ExchangeError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let local = exchanger.send().compress().to_bytes();
+    let mut remote = [0u8; 32];
+
+    if exchanger.role == Role::INITIATOR {
+        transport.write_all(&local).await.map_err(ExchangeError::Io)?;
+        transport.read_exact(&mut remote).await.map_err(ExchangeError::Io)?;
+    } else {
+        transport.read_exact(&mut remote).await.map_err(ExchangeError::Io)?;
+        transport.write_all(&local).await.map_err(ExchangeError::Io)?;
+    }
+
+    let y = CompressedRistretto::from_slice(&remote)
+        .map_err(|_| ExchangeError::InvalidPoint)?
+        .decompress()
+        .ok_or(ExchangeError::InvalidPoint)?;
+
+    Ok(exchanger.receive(y))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::{collections::VecDeque, rc::Rc};
+    use core::{
+        cell::RefCell,
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use futures::executor::block_on;
+    use futures::future::join;
+
+    use super::*;
+    use crate::{Exchanger, Role};
+
+    #[derive(Default)]
+    struct Pipe {
+        buf: VecDeque<u8>,
+        waker: Option<Waker>,
+    }
+
+    /// One end of an in-memory duplex transport, for driving two `Exchanger`s against each other
+    /// without a real socket.
+    struct Half {
+        read: Rc<RefCell<Pipe>>,
+        write: Rc<RefCell<Pipe>>,
+    }
+
+    fn duplex() -> (Half, Half) {
+        let a_to_b = Rc::new(RefCell::new(Pipe::default()));
+        let b_to_a = Rc::new(RefCell::new(Pipe::default()));
+        (
+            Half { read: b_to_a.clone(), write: a_to_b.clone() },
+            Half { read: a_to_b, write: b_to_a },
+        )
+    }
+
+    impl AsyncRead for Half {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<futures::io::Result<usize>> {
+            let mut pipe = self.read.borrow_mut();
+            if pipe.buf.is_empty() {
+                pipe.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            let n = buf.len().min(pipe.buf.len());
+            for slot in buf.iter_mut().take(n) {
+                *slot = pipe.buf.pop_front().expect("checked non-empty");
+            }
+            Poll::Ready(Ok(n))
+        }
+    }
+
+    impl AsyncWrite for Half {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<futures::io::Result<usize>> {
+            let mut pipe = self.write.borrow_mut();
+            pipe.buf.extend(buf.iter().copied());
+            if let Some(waker) = pipe.waker.take() {
+                waker.wake();
+            }
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<futures::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<futures::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn async_exchange_round_trip() {
+        let (mut t_alice, mut t_bea) = duplex();
+
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"secret", b"");
+
+        // The initiator writes-then-reads while the responder reads-then-writes; driving both
+        // futures concurrently over the same duplex is what would deadlock if that ordering were
+        // ever wrong.
+        let (alice, bea) =
+            block_on(join(exchange(alice, &mut t_alice), exchange(bea, &mut t_bea)));
+
+        let mut alice = alice.expect("should exchange");
+        let mut bea = bea.expect("should exchange");
+
+        let mut prf_a = [0u8; 16];
+        alice.prf(&mut prf_a, false);
+
+        let mut prf_b = [0u8; 16];
+        bea.prf(&mut prf_b, false);
+
+        assert_eq!(prf_b, prf_a);
+    }
+
+    #[test]
+    fn async_exchange_rejects_invalid_point() {
+        let (mut t_alice, mut t_bea) = duplex();
+
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"secret", b"");
+
+        // Bea is a well-behaved responder, but Alice's transport is fed a non-canonical point
+        // instead of Bea's real one.
+        t_alice.read.borrow_mut().buf.extend([0xffu8; 32]);
+
+        let (alice, _bea) =
+            block_on(join(exchange(alice, &mut t_alice), exchange(bea, &mut t_bea)));
+
+        assert!(matches!(alice, Err(ExchangeError::InvalidPoint)));
+    }
+}
@@ -17,8 +17,36 @@ pub use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
 use strobe_rs::{SecParam, Strobe};
 
+#[cfg(feature = "hybrid")]
+mod hybrid;
+
+#[cfg(feature = "hybrid")]
+pub use hybrid::{
+    HybridError, HybridInitiator, HybridInitiatorMessage, HybridResponder, HybridResponderMessage,
+};
+
+mod confirm;
+
+pub use confirm::{Confirm, ConfirmError, TAG_LEN};
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod channel;
+
+#[cfg(feature = "alloc")]
+pub use channel::{Channel, ChannelError};
+
+#[cfg(feature = "futures-io")]
+mod io;
+
+#[cfg(feature = "futures-io")]
+pub use io::{exchange, ExchangeError};
+
 /// The role of a given exchanger.
 #[derive(Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Role {
     /// Initiators are exchangers that have initiated the session.
     INITIATOR,
@@ -27,6 +55,14 @@ pub enum Role {
 }
 
 /// An asynchronous PAKE exchange.
+///
+/// With the `serde` feature enabled, an `Exchanger` created by [`Exchanger::new`] can be
+/// serialized and later deserialized to resume an interrupted exchange, e.g. across a process
+/// restart between calling [`Exchanger::send`] and [`Exchanger::receive`]. The serialized form
+/// captures the in-progress STROBE transcript, the role, and the locally generated scalar and
+/// point, and is therefore as secret as the password used to create it: store it encrypted at
+/// rest.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Exchanger {
     role: Role,
     cpace: Strobe,
@@ -112,6 +148,33 @@ impl Exchanger {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn checkpoint_round_trip() {
+        let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");
+        let y_alice = alice.send();
+
+        // Checkpoint Alice's pending exchange, as if the process restarted here, then resume it
+        // from the serialized blob.
+        let checkpoint = serde_json::to_vec(&alice).expect("should serialize");
+        drop(alice);
+        let alice: Exchanger = serde_json::from_slice(&checkpoint).expect("should deserialize");
+
+        let bea = Exchanger::new(Role::RESPONDER, b"Bea", b"Alice", b"secret", b"");
+        let y_bea = bea.send();
+
+        let mut alice = alice.receive(y_bea);
+        let mut bea = bea.receive(y_alice);
+
+        let mut prf_a = [0u8; 16];
+        alice.prf(&mut prf_a, false);
+
+        let mut prf_b = [0u8; 16];
+        bea.prf(&mut prf_b, false);
+
+        assert_eq!(prf_b, prf_a);
+    }
+
     #[test]
     fn full_exchange() {
         let alice = Exchanger::new(Role::INITIATOR, b"Alice", b"Bea", b"secret", b"");